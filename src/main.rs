@@ -1,6 +1,11 @@
 use clap::{command, Parser, Subcommand};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use notify::{Event, RecursiveMode, Watcher};
+use notify_rust::Notification;
 use serde::{Deserialize, Serialize};
 use serde_json;
+use sha2::{Digest, Sha384};
+use std::collections::HashMap;
 use std::env;
 use std::error::Error;
 use std::fs::File;
@@ -8,7 +13,10 @@ use std::io::Write;
 use std::io::{self, Read};
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Parser, Debug)]
 #[command(
@@ -24,7 +32,11 @@ struct Cli {
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Initialize the project
-    Init,
+    Init {
+        /// Project directory to scaffold, if not the current working directory
+        #[arg(long)]
+        entry: Option<PathBuf>,
+    },
     /// Call the configure command
     Configure,
     /// Select current build target
@@ -33,6 +45,29 @@ enum Commands {
     Build,
     /// Run the current build target
     Run,
+    /// Watch the workspace and rebuild (or rerun) on every source change
+    Watch {
+        /// Re-run the current target on each change instead of just building (it is built first only when its `pre_build` is set)
+        #[arg(long)]
+        run: bool,
+    },
+    /// Resolve the current target's command(s) without executing them
+    Plan {
+        #[command(subcommand)]
+        target: PlanTarget,
+    },
+    /// Recompute the current target's artifact digest and compare it against its stored manifest
+    Verify,
+}
+
+#[derive(Subcommand, Debug)]
+enum PlanTarget {
+    /// Resolve the configure command for the current target
+    Configure,
+    /// Resolve the build command for the current target
+    Build,
+    /// Resolve the run command for the current target (expanding pre_build)
+    Run,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -44,6 +79,32 @@ struct CacheJson {
     builds: Vec<BuildJson>,
     runs: Vec<RunJson>,
     configurations: Vec<ConfigureJson>,
+    /// Name of the build system detected for this workspace at `Init` time,
+    /// e.g. "cmake", "make", "meson". Selects the `Backend` (see
+    /// `backend_for`) that configure/build/run dispatch through.
+    #[serde(default = "default_backend_name")]
+    backend: String,
+    /// Directory (relative to the workspace) walked to hash build artifacts
+    /// for the reproducible-build manifest.
+    #[serde(default = "default_artifacts_dir")]
+    artifacts_dir: String,
+}
+
+fn default_backend_name() -> String {
+    "cmake".to_string()
+}
+
+fn default_artifacts_dir() -> String {
+    "build".to_string()
+}
+
+/// A per-OS override of the top-level `command`/`args`, keyed by
+/// `std::env::consts::OS` (e.g. `"linux"`, `"macos"`, `"windows"`).
+#[derive(Serialize, Deserialize)]
+struct CommandSpec {
+    command: String,
+    #[serde(default)]
+    args: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -51,6 +112,8 @@ struct BuildJson {
     name: String,
     command: String,
     args: Vec<String>,
+    #[serde(default)]
+    platforms: HashMap<String, CommandSpec>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -59,6 +122,8 @@ struct RunJson {
     command: String,
     args: Vec<String>,
     pre_build: bool,
+    #[serde(default)]
+    platforms: HashMap<String, CommandSpec>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -66,56 +131,370 @@ struct ConfigureJson {
     name: String,
     command: String,
     args: Vec<String>,
+    #[serde(default)]
+    platforms: HashMap<String, CommandSpec>,
+}
+
+/// Picks the command/args for the current OS if `platforms` has a matching
+/// entry, otherwise falls back to the top-level `command`/`args`. This lets a
+/// single cache file work across Linux/macOS/Windows.
+fn resolve_command<'a>(
+    command: &'a str,
+    args: &'a [String],
+    platforms: &'a HashMap<String, CommandSpec>,
+) -> (&'a str, &'a [String]) {
+    match platforms.get(env::consts::OS) {
+        Some(spec) => (spec.command.as_str(), spec.args.as_slice()),
+        None => (command, args),
+    }
+}
+
+#[cfg(test)]
+mod resolve_command_tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_the_top_level_command_when_no_platform_entry_matches() {
+        let args = vec!["-B".to_string(), "build".to_string()];
+        let mut platforms = HashMap::new();
+        platforms.insert(
+            "not-a-real-os".to_string(),
+            CommandSpec {
+                command: "other".to_string(),
+                args: vec!["ignored".to_string()],
+            },
+        );
+
+        let (command, resolved_args) = resolve_command("cmake", &args, &platforms);
+
+        assert_eq!(command, "cmake");
+        assert_eq!(resolved_args, args.as_slice());
+    }
+
+    #[test]
+    fn uses_the_matching_platform_override_when_present() {
+        let args = vec!["default-arg".to_string()];
+        let mut platforms = HashMap::new();
+        platforms.insert(
+            env::consts::OS.to_string(),
+            CommandSpec {
+                command: "overridden".to_string(),
+                args: vec!["override-arg".to_string()],
+            },
+        );
+
+        let (command, resolved_args) = resolve_command("default", &args, &platforms);
+
+        assert_eq!(command, "overridden");
+        assert_eq!(resolved_args, &["override-arg".to_string()]);
+    }
+}
+
+/// Extension point for build systems beyond the built-in cmake/make/meson
+/// trio: implement `Backend`, add a case to `backend_for`, and every
+/// command (`Configure`/`Build`/`Run`) that dispatches through it picks up
+/// the new tool. `resolve_*` is the single source of truth for the
+/// program/args a target actually spawns — `Plan` and the build manifest
+/// read it too, so a backend that shapes an invocation (e.g. `MakeBackend`
+/// appending `-jN`) stays consistent everywhere instead of only at
+/// execution time. The `configure`/`build`/`run` methods build on it and
+/// additionally execute; override one directly (rather than its matching
+/// `resolve_*`) to validate or reject before running at all.
+pub(crate) trait Backend {
+    /// Matches `CacheJson::backend`, e.g. "cmake".
+    fn name(&self) -> &'static str;
+
+    fn resolve_configure(&self, target: &ConfigureJson) -> (String, Vec<String>) {
+        let (command, args) = resolve_command(&target.command, &target.args, &target.platforms);
+        (command.to_string(), args.to_vec())
+    }
+
+    fn resolve_build(&self, target: &BuildJson) -> (String, Vec<String>) {
+        let (command, args) = resolve_command(&target.command, &target.args, &target.platforms);
+        (command.to_string(), args.to_vec())
+    }
+
+    fn resolve_run(&self, target: &RunJson) -> (String, Vec<String>) {
+        let (command, args) = resolve_command(&target.command, &target.args, &target.platforms);
+        (command.to_string(), args.to_vec())
+    }
+
+    fn configure(&self, workspace: &PathBuf, target: &ConfigureJson) -> Result<(), Box<dyn Error>> {
+        let (command, args) = self.resolve_configure(target);
+        let vec_of_slices: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_command(workspace, &target.name, &command, &vec_of_slices)
+    }
+
+    fn build(&self, workspace: &PathBuf, target: &BuildJson) -> Result<(), Box<dyn Error>> {
+        let (command, args) = self.resolve_build(target);
+        let vec_of_slices: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_command(workspace, &target.name, &command, &vec_of_slices)
+    }
+
+    fn run(&self, workspace: &PathBuf, target: &RunJson) -> Result<(), Box<dyn Error>> {
+        let (command, args) = self.resolve_run(target);
+        let vec_of_slices: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_command(workspace, &target.name, &command, &vec_of_slices)
+    }
 }
 
-impl BuildJson {
-    fn build(&self, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
-        let vec_of_slices: Vec<&str> = self.args.iter().map(|s| s.as_str()).collect();
-        run_command(workspace, &self.command, &vec_of_slices)
+/// cmake/ninja: refuses to configure a workspace without a top-level
+/// `CMakeLists.txt`, since handing that straight to `cmake` produces a
+/// confusing "Could not find CMAKE_ROOT" error instead of a clear one.
+pub(crate) struct CMakeBackend;
+
+impl Backend for CMakeBackend {
+    fn name(&self) -> &'static str {
+        "cmake"
+    }
+
+    fn configure(&self, workspace: &PathBuf, target: &ConfigureJson) -> Result<(), Box<dyn Error>> {
+        if !workspace.join("CMakeLists.txt").exists() {
+            return Err(format!(
+                "cmake backend: no CMakeLists.txt in {}",
+                workspace.display()
+            )
+            .into());
+        }
+        let (command, args) = self.resolve_configure(target);
+        let vec_of_slices: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_command(workspace, &target.name, &command, &vec_of_slices)
     }
 }
 
-impl RunJson {
-    fn run(&self, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
-        let vec_of_slices: Vec<&str> = self.args.iter().map(|s| s.as_str()).collect();
-        run_command(workspace, &self.command, &vec_of_slices)
+/// meson/ninja: same missing-manifest guard as `CMakeBackend`, keyed off
+/// `meson.build` instead.
+pub(crate) struct MesonBackend;
+
+impl Backend for MesonBackend {
+    fn name(&self) -> &'static str {
+        "meson"
+    }
+
+    fn configure(&self, workspace: &PathBuf, target: &ConfigureJson) -> Result<(), Box<dyn Error>> {
+        if !workspace.join("meson.build").exists() {
+            return Err(format!(
+                "meson backend: no meson.build in {}",
+                workspace.display()
+            )
+            .into());
+        }
+        let (command, args) = self.resolve_configure(target);
+        let vec_of_slices: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+        run_command(workspace, &target.name, &command, &vec_of_slices)
     }
 }
 
-impl ConfigureJson {
-    fn configure(&self, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
-        let vec_of_slices: Vec<&str> = self.args.iter().map(|s| s.as_str()).collect();
-        run_command(workspace, &self.command, &vec_of_slices)
+/// make/autotools: a bare `make` invocation builds single-threaded, so
+/// unless the entry's own `args` already request a `-j`/`--jobs` flag,
+/// `MakeBackend` appends `-jN` for the host's available parallelism.
+pub(crate) struct MakeBackend;
+
+impl Backend for MakeBackend {
+    fn name(&self) -> &'static str {
+        "make"
+    }
+
+    fn resolve_build(&self, target: &BuildJson) -> (String, Vec<String>) {
+        let (command, args) = resolve_command(&target.command, &target.args, &target.platforms);
+        let mut owned_args: Vec<String> = args.to_vec();
+        let requests_jobs = owned_args
+            .iter()
+            .any(|arg| arg == "-j" || arg.starts_with("-j") || arg == "--jobs" || arg.starts_with("--jobs="));
+        if command == "make" && !requests_jobs {
+            let jobs = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+            owned_args.push(format!("-j{}", jobs));
+        }
+        (command.to_string(), owned_args)
     }
 }
 
-fn run_command(workspace: &PathBuf, command: &str, args: &[&str]) -> Result<(), Box<dyn Error>> {
+/// Backend for a `CacheJson::backend` value that doesn't match a built-in
+/// tool: every method just delegates to the entry's own resolved
+/// `command`/`args`, with no extra validation or shaping.
+pub(crate) struct GenericBackend;
+
+impl Backend for GenericBackend {
+    fn name(&self) -> &'static str {
+        "generic"
+    }
+}
+
+/// Resolves `CacheJson::backend` to its `Backend` implementation, falling
+/// back to `GenericBackend` for an unrecognized name rather than failing
+/// the command outright.
+fn backend_for(name: &str) -> Box<dyn Backend> {
+    match name {
+        "cmake" => Box::new(CMakeBackend),
+        "meson" => Box::new(MesonBackend),
+        "make" => Box::new(MakeBackend),
+        _ => Box::new(GenericBackend),
+    }
+}
+
+/// Spawns `command`, piping stdout/stderr so both can be drained concurrently
+/// (one thread per stream) instead of blocking on one before the other. Each
+/// line is prefixed with `label` (the target name) so multi-step logs read
+/// clearly, and a non-zero exit distinguishes a plain exit code from
+/// termination by signal.
+fn run_command(
+    workspace: &PathBuf,
+    label: &str,
+    command: &str,
+    args: &[&str],
+) -> Result<(), Box<dyn Error>> {
+    run_command_to(workspace, label, command, args, io::stdout(), io::stderr())
+}
+
+/// Does the actual work for `run_command`, writing prefixed stdout/stderr
+/// lines to `stdout_sink`/`stderr_sink` instead of the process's real
+/// stdout/stderr. Split out so tests can assert on captured output instead of
+/// whatever happens to be attached to the test process's file descriptors.
+fn run_command_to<O, E>(
+    workspace: &PathBuf,
+    label: &str,
+    command: &str,
+    args: &[&str],
+    mut stdout_sink: O,
+    mut stderr_sink: E,
+) -> Result<(), Box<dyn Error>>
+where
+    O: Write + Send + 'static,
+    E: Write + Send + 'static,
+{
     let mut child = Command::new(command)
         .args(args)
         .current_dir(workspace)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .spawn()?;
 
-    // Process stdout
-    if let Some(stdout) = child.stdout.take() {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines() {
-            println!("{}", line?);
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let stderr = child.stderr.take().expect("stderr was piped");
+
+    let stdout_label = label.to_string();
+    let stdout_handle = thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            match line {
+                Ok(line) => {
+                    let _ = writeln!(stdout_sink, "[{}] {}", stdout_label, line);
+                }
+                Err(err) => eprintln!("[{}] failed to read stdout: {}", stdout_label, err),
+            }
+        }
+    });
+
+    let stderr_label = label.to_string();
+    let stderr_handle = thread::spawn(move || {
+        for line in BufReader::new(stderr).lines() {
+            match line {
+                Ok(line) => {
+                    let _ = writeln!(stderr_sink, "[{}] {}", stderr_label, line);
+                }
+                Err(err) => eprintln!("[{}] failed to read stderr: {}", stderr_label, err),
+            }
+        }
+    });
+
+    let status = child.wait()?;
+    stdout_handle.join().expect("stdout reader thread panicked");
+    stderr_handle.join().expect("stderr reader thread panicked");
+
+    if status.success() {
+        return Ok(());
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return Err(format!("Command '{}' terminated by signal {}", label, signal).into());
         }
     }
 
-    // Process stderr
-    if let Some(stderr) = child.stderr.take() {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines() {
-            eprintln!("{}", line?);
+    let code = status
+        .code()
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    Err(format!("Command '{}' failed with exit code: {}", label, code).into())
+}
+
+#[cfg(test)]
+mod run_command_tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// An in-memory `Write` sink shared between the test and the reader
+    /// thread it's moved into, so the test can inspect captured output after
+    /// `run_command_to` returns.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuffer {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
         }
     }
 
-    let status = child.wait()?;
-    if !status.success() {
-        return Err(format!("Command failed with status: {}", status).into());
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn prefixes_and_separates_stdout_and_stderr_lines() {
+        let stdout_sink = SharedBuffer::default();
+        let stderr_sink = SharedBuffer::default();
+
+        let result = run_command_to(
+            &PathBuf::from("."),
+            "demo",
+            "sh",
+            &["-c", "echo out1; echo err1 1>&2; echo out2"],
+            stdout_sink.clone(),
+            stderr_sink.clone(),
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(stdout_sink.contents(), "[demo] out1\n[demo] out2\n");
+        assert_eq!(stderr_sink.contents(), "[demo] err1\n");
+    }
+
+    #[test]
+    fn reports_exit_code_on_plain_failure() {
+        let result = run_command_to(
+            &PathBuf::from("."),
+            "demo",
+            "sh",
+            &["-c", "exit 3"],
+            SharedBuffer::default(),
+            SharedBuffer::default(),
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert_eq!(err, "Command 'demo' failed with exit code: 3");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_signal_on_termination_by_signal() {
+        let result = run_command_to(
+            &PathBuf::from("."),
+            "demo",
+            "sh",
+            // Signals itself with SIGKILL (9) instead of exiting normally.
+            &["-c", "kill -KILL $$"],
+            SharedBuffer::default(),
+            SharedBuffer::default(),
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert_eq!(err, "Command 'demo' terminated by signal 9");
     }
-    Ok(())
 }
 
 fn confirm_overwrite() -> bool {
@@ -127,8 +506,124 @@ fn confirm_overwrite() -> bool {
     matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
 }
 
-/// * `workspace` - cmake project workspace
-/// * `path` - path where the json should be created
+/// Marker files probed, in order, to infer which build system `workspace`
+/// uses. The first marker found picks the backend.
+const BUILD_SYSTEM_MARKERS: &[(&str, &str)] = &[
+    ("CMakeLists.txt", "cmake"),
+    ("meson.build", "meson"),
+    ("Makefile", "make"),
+    ("configure", "make"),
+];
+
+fn detect_backend(workspace: &PathBuf) -> &'static str {
+    for (marker, backend) in BUILD_SYSTEM_MARKERS {
+        if workspace.join(marker).exists() {
+            return backend;
+        }
+    }
+    "cmake"
+}
+
+/// Like `detect_backend`, but returns the marker file itself rather than the
+/// (possibly collapsed) backend it maps to, so callers that need to tell
+/// `Makefile` and `configure` apart — both of which map to the `"make"`
+/// backend — still can.
+fn detect_build_system_marker(workspace: &PathBuf) -> Option<&'static str> {
+    for (marker, _) in BUILD_SYSTEM_MARKERS {
+        if workspace.join(marker).exists() {
+            return Some(marker);
+        }
+    }
+    None
+}
+
+/// Default configure command/args for the detected marker, e.g.
+/// `cmake -S . -B build`. `Makefile` has no configure step of its own, but a
+/// bare `configure` script does (autotools) and must run `./configure`
+/// rather than the `Makefile` case's no-op.
+fn default_configure_command(marker: Option<&str>) -> (&'static str, Vec<String>) {
+    match marker {
+        Some("meson.build") => ("meson", vec!["setup".to_string(), "build".to_string()]),
+        Some("configure") => ("./configure", Vec::new()),
+        Some("Makefile") => ("true", Vec::new()),
+        _ => (
+            "cmake",
+            vec!["-S".to_string(), ".".to_string(), "-B".to_string(), "build".to_string()],
+        ),
+    }
+}
+
+/// Default build command/args for a backend, e.g. `cmake --build build`.
+fn default_build_command(backend: &str) -> (&'static str, Vec<String>) {
+    match backend {
+        "meson" => (
+            "meson",
+            vec!["compile".to_string(), "-C".to_string(), "build".to_string()],
+        ),
+        "make" => ("make", Vec::new()),
+        _ => ("cmake", vec!["--build".to_string(), "build".to_string()]),
+    }
+}
+
+/// Default path to the binary a scaffolded `Run` entry should point at.
+/// cmake/meson configure into `build/`, so their binaries land there; a
+/// `make`/autotools build (run from the workspace root, per
+/// `default_build_command`) typically drops its binary in the workspace
+/// root instead.
+fn default_run_binary(workspace: &PathBuf, backend: &str, name: &str) -> PathBuf {
+    match backend {
+        "make" => workspace.join(name),
+        _ => workspace.join("build").join(name),
+    }
+}
+
+/// Default `CacheJson::artifacts_dir` for a backend, relative to the
+/// workspace. Must stay in lockstep with `default_run_binary`: a `make`
+/// build drops its binary at the workspace root, so hashing `build/` for
+/// its manifest would silently hash nothing.
+fn default_artifacts_dir_for(backend: &str) -> String {
+    match backend {
+        "make" => ".".to_string(),
+        _ => default_artifacts_dir(),
+    }
+}
+
+/// Parses the top-level `CMakeLists.txt`'s `add_executable(name ...)` calls
+/// to pre-populate build targets. Returns an empty vec if the file is absent
+/// or has no executables (e.g. a library-only project).
+fn parse_cmake_executables(workspace: &PathBuf) -> Vec<String> {
+    let contents = match std::fs::read_to_string(workspace.join("CMakeLists.txt")) {
+        Ok(contents) => contents,
+        Err(_) => return Vec::new(),
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("add_executable("))
+        .filter_map(|rest| rest.split_whitespace().next())
+        .map(|name| name.trim_end_matches(')').to_string())
+        .collect()
+}
+
+/// Parses the top-level `CMakeLists.txt`'s `project(name ...)` call, used as
+/// a fallback target name when no `add_executable` entries are found.
+fn parse_cmake_project_name(workspace: &PathBuf) -> Option<String> {
+    let contents = std::fs::read_to_string(workspace.join("CMakeLists.txt")).ok()?;
+    contents
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("project("))
+        .and_then(|rest| rest.split_whitespace().next())
+        .map(|name| name.trim_end_matches(')').to_string())
+}
+
+fn fallback_target_name(workspace: &PathBuf) -> String {
+    parse_cmake_project_name(workspace)
+        .or_else(|| workspace.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "app".to_string())
+}
+
+/// * `workspace` - project directory scanned for build-system markers and targets
+/// * `json_path` - path where the json should be created
 /// # Panics
 /// Panics if cannot read/write into ~/.cache/CMakeForge/ path
 fn create_json_in_workspace(json_path: &PathBuf, workspace: &PathBuf) {
@@ -140,44 +635,63 @@ fn create_json_in_workspace(json_path: &PathBuf, workspace: &PathBuf) {
     }
     let mut file = File::create(json_path).unwrap();
 
+    let backend = detect_backend(workspace);
+    let configure_marker = detect_build_system_marker(workspace);
+    let mut build_targets = parse_cmake_executables(workspace);
+    if build_targets.is_empty() {
+        build_targets.push(fallback_target_name(workspace));
+    }
+
+    let (configure_command, configure_args) = default_configure_command(configure_marker);
+    let (build_command, build_args) = default_build_command(backend);
+
+    let configurations = build_targets
+        .iter()
+        .map(|name| ConfigureJson {
+            name: name.clone(),
+            command: configure_command.to_string(),
+            args: configure_args.clone(),
+            platforms: HashMap::new(),
+        })
+        .collect();
+
+    let builds = build_targets
+        .iter()
+        .map(|name| BuildJson {
+            name: name.clone(),
+            command: build_command.to_string(),
+            args: build_args.clone(),
+            platforms: HashMap::new(),
+        })
+        .collect();
+
+    let runs = build_targets
+        .iter()
+        .map(|name| RunJson {
+            name: name.clone(),
+            command: default_run_binary(workspace, backend, name)
+                .to_string_lossy()
+                .into_owned(),
+            args: Vec::new(),
+            pre_build: true,
+            platforms: HashMap::new(),
+        })
+        .collect();
+
     let cache = CacheJson {
         workspace: workspace.to_string_lossy().into_owned(),
-        build_targets: vec!["test1".to_string(), "test2".to_string()],
-        current_build_target: "test1".to_string(),
-        // create builds vector with data
-        builds: vec![BuildJson {
-            name: "test1".to_string(),
-            command: "cmake ..".to_string(),
-            args: vec!["-DCMAKE_BUILD_TYPE=Debug".to_string()],
-        }],
-        runs: vec![
-            RunJson {
-                name: "test1".to_string(),
-                command: "/my/super/app".to_string(),
-                args: vec!["--arg1".to_string(), "--arg2".to_string()],
-                pre_build: true,
-            },
-            RunJson {
-                name: "test2".to_string(),
-                command: "/my/super/app".to_string(),
-                args: vec!["--arg1".to_string(), "--arg2".to_string()],
-                pre_build: true,
-            },
-        ],
-        configurations: vec![ConfigureJson {
-            name: "test1".to_string(),
-            command: "cmake".to_string(),
-            args: vec![
-                "-DCMAKE_BUILD_TYPE=Debug".to_string(),
-                "-DCMAKE_EXPORT_COMPILE_COMMANDS=ON".to_string(),
-                "-G".to_string(),
-                "Ninja".to_string(),
-            ],
-        }],
+        current_build_target: build_targets[0].clone(),
+        build_targets,
+        builds,
+        runs,
+        configurations,
+        backend: backend.to_string(),
+        artifacts_dir: default_artifacts_dir_for(backend),
     };
     let json_string = serde_json::to_string_pretty(&cache).expect("Failed to serialize");
     println!(
-        "Creating json file config for cmake in: {}",
+        "Creating json file config for {} in: {}",
+        backend,
         json_path.to_str().unwrap()
     );
     file.write_all(json_string.as_bytes()).unwrap();
@@ -234,12 +748,12 @@ fn build_current_target(json_path: &PathBuf, workspace: &PathBuf) -> Result<(),
 
     let cache: CacheJson = serde_json::from_str(&contents)?;
     println!("Current build target: {}", cache.current_build_target);
+    let backend = backend_for(&cache.backend);
     // From the 'builds' extract the build target
     for curr_build in &cache.builds {
         if curr_build.name == cache.current_build_target {
-            println!("Building {}", curr_build.name);
-            // Add your build logic here
-            return curr_build.build(workspace);
+            println!("Building {} ({} backend)", curr_build.name, backend.name());
+            return backend.build(workspace, curr_build);
         }
     }
     Err(format!("Build target not found: {}", cache.current_build_target).into())
@@ -248,15 +762,15 @@ fn build_current_target(json_path: &PathBuf, workspace: &PathBuf) -> Result<(),
 fn run_current_target(json_path: &PathBuf, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
     let cache: CacheJson = read_cache(json_path)?;
     println!("Current run target: {}", cache.current_build_target);
+    let backend = backend_for(&cache.backend);
     // From the 'builds' extract the build target
     for curr_run in &cache.runs {
         if curr_run.name == cache.current_build_target {
             if curr_run.pre_build {
                 build_current_target(json_path, workspace)?;
             }
-            println!("Running {}", curr_run.name);
-            // Add your run logic here
-            return curr_run.run(workspace);
+            println!("Running {} ({} backend)", curr_run.name, backend.name());
+            return backend.run(workspace, curr_run);
         }
     }
     Err(format!("Run target not found: {}", cache.current_build_target).into())
@@ -268,30 +782,550 @@ fn configure_current_build_target(
 ) -> Result<(), Box<dyn Error>> {
     let cache: CacheJson = read_cache(json_path)?;
     println!("Current build target: {}", cache.current_build_target);
+    let backend = backend_for(&cache.backend);
     for curr_config in &cache.configurations {
         if curr_config.name == cache.current_build_target {
-            println!("Configuring {}", curr_config.name);
-            // Add your configure logic here
-            return curr_config.configure(workspace);
+            println!(
+                "Configuring {} ({} backend)",
+                curr_config.name,
+                backend.name()
+            );
+            return backend.configure(workspace, curr_config);
         }
     }
     Err(format!("Configure target not found: {}", cache.current_build_target).into())
 }
 
-fn cli_parser(workspace: &PathBuf, json_path: &PathBuf) -> Result<(), Box<dyn Error>> {
-    let cli = Cli::parse();
+/// A single resolved invocation that `run_command` would spawn: the program,
+/// its argument vector, and the working directory. Used by the `Plan`
+/// subcommand so CI systems and editors can inspect what would run.
+#[derive(Serialize)]
+struct PlannedCommand {
+    program: String,
+    args: Vec<String>,
+    cwd: String,
+}
+
+impl PlannedCommand {
+    fn new(workspace: &PathBuf, command: &str, args: &[String]) -> Self {
+        PlannedCommand {
+            program: command.to_string(),
+            args: args.to_vec(),
+            cwd: workspace.to_string_lossy().into_owned(),
+        }
+    }
+}
+
+/// Resolves through `backend_for(&cache.backend)` rather than calling
+/// `resolve_command` directly, so a plan reflects any backend-specific
+/// argument shaping (e.g. `MakeBackend`'s `-jN`) exactly as `build_current_target`
+/// would apply it.
+fn plan_configure(json_path: &PathBuf, workspace: &PathBuf) -> Result<Vec<PlannedCommand>, Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let backend = backend_for(&cache.backend);
+    for curr_config in &cache.configurations {
+        if curr_config.name == cache.current_build_target {
+            let (command, args) = backend.resolve_configure(curr_config);
+            return Ok(vec![PlannedCommand::new(workspace, &command, &args)]);
+        }
+    }
+    Err(format!("Configure target not found: {}", cache.current_build_target).into())
+}
+
+fn plan_build(json_path: &PathBuf, workspace: &PathBuf) -> Result<Vec<PlannedCommand>, Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let backend = backend_for(&cache.backend);
+    for curr_build in &cache.builds {
+        if curr_build.name == cache.current_build_target {
+            let (command, args) = backend.resolve_build(curr_build);
+            return Ok(vec![PlannedCommand::new(workspace, &command, &args)]);
+        }
+    }
+    Err(format!("Build target not found: {}", cache.current_build_target).into())
+}
+
+/// Resolves the run target's invocation, prepending the build invocation when
+/// `pre_build` is set so the plan mirrors what `run_current_target` executes.
+fn plan_run(json_path: &PathBuf, workspace: &PathBuf) -> Result<Vec<PlannedCommand>, Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let backend = backend_for(&cache.backend);
+    for curr_run in &cache.runs {
+        if curr_run.name == cache.current_build_target {
+            let mut planned = Vec::new();
+            if curr_run.pre_build {
+                planned.extend(plan_build(json_path, workspace)?);
+            }
+            let (command, args) = backend.resolve_run(curr_run);
+            planned.push(PlannedCommand::new(workspace, &command, &args));
+            return Ok(planned);
+        }
+    }
+    Err(format!("Run target not found: {}", cache.current_build_target).into())
+}
+
+fn print_plan(planned: &[PlannedCommand]) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(planned)?);
+    Ok(())
+}
+
+/// Reproducible-build provenance record written next to the cache JSON after
+/// a successful `Build`. `digest` is a SHA-384 hash over every file under
+/// `artifacts_dir`, walked in a stable sorted order so the same build output
+/// always produces the same digest.
+#[derive(Serialize, Deserialize)]
+struct BuildManifest {
+    target: String,
+    command: String,
+    args: Vec<String>,
+    timestamp: u64,
+    digest: String,
+}
+
+fn manifest_path(json_path: &PathBuf, target: &str) -> PathBuf {
+    let stem = json_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "cmakeforge".to_string());
+    json_path.with_file_name(format!("{}.{}.manifest.json", stem, target))
+}
+
+fn signature_path(manifest_path: &PathBuf) -> PathBuf {
+    let mut path = manifest_path.clone().into_os_string();
+    path.push(".sig");
+    PathBuf::from(path)
+}
+
+/// Hashes every file under `dir` in sorted, relative-path order so the digest
+/// is stable regardless of filesystem iteration order. Missing directories
+/// (e.g. a build that produced no artifacts) hash to the empty digest.
+fn hash_artifacts(dir: &PathBuf) -> Result<String, Box<dyn Error>> {
+    let mut paths = Vec::new();
+    collect_files(dir, &mut paths)?;
+    paths.sort();
+
+    let mut hasher = Sha384::new();
+    for path in &paths {
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        let mut file = File::open(path)?;
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        hasher.update(&contents);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn collect_files(dir: &PathBuf, paths: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Writes a signed, detached signature for `manifest_bytes` when
+/// `CMAKEFORGE_SIGNING_KEY` points at a file holding a 32-byte ed25519 seed.
+/// Absence of the variable silently skips signing, since it's optional.
+fn sign_manifest(manifest_path: &PathBuf, manifest_bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+    let key_path = match env::var("CMAKEFORGE_SIGNING_KEY") {
+        Ok(path) => path,
+        Err(_) => return Ok(()),
+    };
+
+    let mut seed = [0u8; 32];
+    let mut key_file = File::open(key_path)?;
+    key_file.read_exact(&mut seed)?;
+
+    let signing_key = SigningKey::from_bytes(&seed);
+    let signature: Signature = signing_key.sign(manifest_bytes);
+
+    let mut sig_file = File::create(signature_path(manifest_path))?;
+    sig_file.write_all(hex::encode(signature.to_bytes()).as_bytes())?;
+    Ok(())
+}
+
+/// Verifies the manifest's detached signature when `CMAKEFORGE_VERIFY_KEY`
+/// points at a file holding a 32-byte ed25519 public key. Absence of the
+/// variable silently skips verification; with it set, a missing `.sig` file
+/// next to the manifest is an error.
+fn verify_manifest_signature(manifest_path: &PathBuf, manifest_bytes: &[u8]) -> Result<(), Box<dyn Error>> {
+    let key_path = match env::var("CMAKEFORGE_VERIFY_KEY") {
+        Ok(path) => path,
+        Err(_) => return Ok(()),
+    };
+    let sig_path = signature_path(manifest_path);
+    if !sig_path.exists() {
+        return Err(format!("No signature found at {}", sig_path.display()).into());
+    }
+
+    let mut key_bytes = [0u8; 32];
+    File::open(key_path)?.read_exact(&mut key_bytes)?;
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes)?;
+
+    let mut sig_hex = String::new();
+    File::open(&sig_path)?.read_to_string(&mut sig_hex)?;
+    let sig_bytes = hex::decode(sig_hex.trim())?;
+    let signature = Signature::from_slice(&sig_bytes)?;
+
+    verifying_key.verify(manifest_bytes, &signature)?;
+    println!("Signature OK: {}", sig_path.display());
+    Ok(())
+}
+
+/// Generates and writes the `BuildManifest` for the current target after a
+/// successful build, signing it when a signing key is configured.
+fn generate_build_manifest(json_path: &PathBuf, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let curr_build = cache
+        .builds
+        .iter()
+        .find(|build| build.name == cache.current_build_target)
+        .ok_or_else(|| format!("Build target not found: {}", cache.current_build_target))?;
+
+    let (command, args) = backend_for(&cache.backend).resolve_build(curr_build);
+    let digest = hash_artifacts(&workspace.join(&cache.artifacts_dir))?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+    let manifest = BuildManifest {
+        target: cache.current_build_target.clone(),
+        command,
+        args,
+        timestamp,
+        digest,
+    };
+
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+    let path = manifest_path(json_path, &cache.current_build_target);
+    File::create(&path)?.write_all(&manifest_bytes)?;
+    sign_manifest(&path, &manifest_bytes)?;
+
+    println!("Wrote build manifest: {}", path.display());
+    Ok(())
+}
+
+/// Recomputes the current target's artifact digest and compares it against
+/// the manifest written by `generate_build_manifest`.
+fn verify_build_manifest(json_path: &PathBuf, workspace: &PathBuf) -> Result<(), Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let path = manifest_path(json_path, &cache.current_build_target);
+    if !path.exists() {
+        return Err(format!("No manifest found at {}", path.display()).into());
+    }
+
+    let mut contents = String::new();
+    File::open(&path)?.read_to_string(&mut contents)?;
+    let manifest: BuildManifest = serde_json::from_str(&contents)?;
+
+    verify_manifest_signature(&path, contents.as_bytes())?;
+
+    let digest = hash_artifacts(&workspace.join(&cache.artifacts_dir))?;
+    if digest != manifest.digest {
+        return Err(format!(
+            "Digest mismatch for {}: expected {}, got {}",
+            manifest.target, manifest.digest, digest
+        )
+        .into());
+    }
+
+    println!("Digest OK for {}: {}", manifest.target, digest);
+    Ok(())
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// `sign_manifest`/`verify_manifest_signature` key off the
+    /// process-global `CMAKEFORGE_SIGNING_KEY`/`CMAKEFORGE_VERIFY_KEY` env
+    /// vars; serialize the tests that touch them so concurrent test
+    /// threads don't stomp on each other's value.
+    static ENV_GUARD: Mutex<()> = Mutex::new(());
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!(
+            "cmakeforge-test-{}-{}-{}",
+            label,
+            std::process::id(),
+            nanos
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn hash_artifacts_is_stable_regardless_of_write_order() {
+        let dir = temp_dir("digest-stable");
+        std::fs::write(dir.join("b.txt"), b"second").unwrap();
+        std::fs::write(dir.join("a.txt"), b"first").unwrap();
+        let other_dir = temp_dir("digest-stable-other");
+        std::fs::write(other_dir.join("a.txt"), b"first").unwrap();
+        std::fs::write(other_dir.join("b.txt"), b"second").unwrap();
+
+        let first = hash_artifacts(&dir).unwrap();
+        let second = hash_artifacts(&other_dir).unwrap();
+
+        assert_eq!(first, second);
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::remove_dir_all(&other_dir);
+    }
+
+    #[test]
+    fn hash_artifacts_of_missing_dir_is_the_empty_digest() {
+        let dir = std::env::temp_dir().join("cmakeforge-test-does-not-exist");
+        let _ = std::fs::remove_dir_all(&dir);
+        let expected = hex::encode(Sha384::new().finalize());
+
+        assert_eq!(hash_artifacts(&dir).unwrap(), expected);
+    }
+
+    #[test]
+    fn hash_artifacts_changes_when_a_file_is_tampered_with() {
+        let dir = temp_dir("digest-tamper");
+        std::fs::write(dir.join("out.bin"), b"original").unwrap();
+        let before = hash_artifacts(&dir).unwrap();
+
+        std::fs::write(dir.join("out.bin"), b"tampered").unwrap();
+        let after = hash_artifacts(&dir).unwrap();
+
+        assert_ne!(before, after);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn sign_and_verify_manifest_round_trip() {
+        let _guard = ENV_GUARD.lock().unwrap();
+        let dir = temp_dir("sign-verify");
+
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let verifying_key = signing_key.verifying_key();
+        let key_path = dir.join("signing.key");
+        let pub_path = dir.join("verify.key");
+        std::fs::write(&key_path, signing_key.to_bytes()).unwrap();
+        std::fs::write(&pub_path, verifying_key.to_bytes()).unwrap();
+
+        let manifest_path = dir.join("target.manifest.json");
+        let manifest_bytes = b"fake manifest contents";
+
+        std::env::set_var("CMAKEFORGE_SIGNING_KEY", &key_path);
+        let sign_result = sign_manifest(&manifest_path, manifest_bytes);
+        std::env::remove_var("CMAKEFORGE_SIGNING_KEY");
+        sign_result.unwrap();
+        assert!(signature_path(&manifest_path).exists());
+
+        std::env::set_var("CMAKEFORGE_VERIFY_KEY", &pub_path);
+        let verify_result = verify_manifest_signature(&manifest_path, manifest_bytes);
+        std::env::remove_var("CMAKEFORGE_VERIFY_KEY");
+
+        assert!(verify_result.is_ok());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn verify_rejects_a_manifest_whose_bytes_were_tampered_with() {
+        let _guard = ENV_GUARD.lock().unwrap();
+        let dir = temp_dir("sign-tamper");
+
+        let signing_key = SigningKey::from_bytes(&[9u8; 32]);
+        let verifying_key = signing_key.verifying_key();
+        let key_path = dir.join("signing.key");
+        let pub_path = dir.join("verify.key");
+        std::fs::write(&key_path, signing_key.to_bytes()).unwrap();
+        std::fs::write(&pub_path, verifying_key.to_bytes()).unwrap();
+
+        let manifest_path = dir.join("target.manifest.json");
+
+        std::env::set_var("CMAKEFORGE_SIGNING_KEY", &key_path);
+        let sign_result = sign_manifest(&manifest_path, b"original manifest contents");
+        std::env::remove_var("CMAKEFORGE_SIGNING_KEY");
+        sign_result.unwrap();
+
+        std::env::set_var("CMAKEFORGE_VERIFY_KEY", &pub_path);
+        let verify_result =
+            verify_manifest_signature(&manifest_path, b"tampered manifest contents!");
+        std::env::remove_var("CMAKEFORGE_VERIFY_KEY");
+
+        assert!(verify_result.is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn build_then_verify_round_trip_detects_artifact_tampering() {
+        let _guard = ENV_GUARD.lock().unwrap();
+        let dir = temp_dir("build-verify");
+        let artifacts_dir = dir.join("build");
+        std::fs::create_dir_all(&artifacts_dir).unwrap();
+        std::fs::write(artifacts_dir.join("app"), b"binary contents").unwrap();
+
+        let cache = CacheJson {
+            workspace: dir.to_string_lossy().into_owned(),
+            build_targets: vec!["app".to_string()],
+            current_build_target: "app".to_string(),
+            builds: vec![BuildJson {
+                name: "app".to_string(),
+                command: "true".to_string(),
+                args: Vec::new(),
+                platforms: HashMap::new(),
+            }],
+            runs: Vec::new(),
+            configurations: Vec::new(),
+            backend: "cmake".to_string(),
+            artifacts_dir: "build".to_string(),
+        };
+        let json_path = dir.join("cache.json");
+        std::fs::write(&json_path, serde_json::to_string_pretty(&cache).unwrap()).unwrap();
+
+        generate_build_manifest(&json_path, &dir).unwrap();
+        assert!(verify_build_manifest(&json_path, &dir).is_ok());
+
+        std::fs::write(artifacts_dir.join("app"), b"tampered contents").unwrap();
+        let err = verify_build_manifest(&json_path, &dir).unwrap_err();
+        assert!(err.to_string().contains("Digest mismatch"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
+
+/// How long to wait after the first filesystem event before rebuilding, so a
+/// single save of multiple files (or a `git checkout`) only triggers one build.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches `workspace` for source changes and re-triggers `build_current_target`
+/// (or `run_current_target` when `run` is set) until interrupted with Ctrl-C.
+///
+/// Events under the build output directory (`CacheJson::artifacts_dir`,
+/// relative to `workspace`) and the CMakeForge cache directory (the parent of
+/// `json_path`) are ignored so that artifacts produced by the rebuild itself
+/// don't re-trigger the watcher. When `artifacts_dir` is the workspace root
+/// itself (as for a `make` build), it is not ignored, so files that build
+/// writes there also trigger a rebuild.
+fn watch_workspace(json_path: &PathBuf, workspace: &PathBuf, run: bool) -> Result<(), Box<dyn Error>> {
+    let cache = read_cache(json_path)?;
+    let ignored_build_dir =
+        Some(workspace.join(&cache.artifacts_dir)).filter(|dir| dir != workspace);
+    let ignored_cache_dir = json_path
+        .parent()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace.clone());
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(workspace, RecursiveMode::Recursive)?;
+
+    println!(
+        "Watching {} for changes (Ctrl-C to stop)...",
+        workspace.display()
+    );
+
+    loop {
+        let event = match rx.recv() {
+            Ok(event) => event,
+            Err(_) => break,
+        };
+
+        match event {
+            Ok(event) if event_is_ignored(&event, ignored_build_dir.as_ref(), &ignored_cache_dir) => {
+                continue;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("Watch error: {}", err);
+                continue;
+            }
+        }
+
+        // Coalesce the burst of events a single save usually produces into one rebuild.
+        thread::sleep(WATCH_DEBOUNCE);
+        while rx.try_recv().is_ok() {}
+
+        trigger_watch_build(json_path, workspace, run);
+    }
+
+    Ok(())
+}
+
+fn event_is_ignored(event: &Event, ignored_build_dir: Option<&PathBuf>, ignored_cache_dir: &PathBuf) -> bool {
+    event.paths.iter().all(|path| {
+        ignored_build_dir.map_or(false, |dir| path.starts_with(dir))
+            || path.starts_with(ignored_cache_dir)
+    })
+}
+
+fn trigger_watch_build(json_path: &PathBuf, workspace: &PathBuf, run: bool) {
+    let target_name = match read_cache(json_path) {
+        Ok(cache) => cache.current_build_target,
+        Err(err) => {
+            eprintln!("Failed to read cache: {}", err);
+            return;
+        }
+    };
+
+    let verb = if run { "Run" } else { "Build" };
+    let result = if run {
+        run_current_target(json_path, workspace)
+    } else {
+        build_current_target(json_path, workspace)
+    };
+
+    let (summary, success) = match &result {
+        Ok(()) => (format!("{} succeeded: {}", verb, target_name), true),
+        Err(err) => (format!("{} failed: {} ({})", verb, target_name, err), false),
+    };
+    println!("{}", summary);
+
+    let notification = Notification::new()
+        .summary(if success {
+            "CMakeForge: build succeeded"
+        } else {
+            "CMakeForge: build failed"
+        })
+        .body(&summary)
+        .show();
+    if let Err(err) = notification {
+        eprintln!("Failed to send desktop notification: {}", err);
+    }
+}
+
+fn cli_parser(cli: Cli, workspace: &PathBuf, json_path: &PathBuf) -> Result<(), Box<dyn Error>> {
     if json_path.exists() {
         println!("Loading json: {}", json_path.display());
     }
+
+    // Every command other than `Init` operates on the workspace recorded in
+    // the cache (set at `Init` time, possibly via `--entry`) rather than
+    // wherever the CLI happens to be invoked from, so `init --entry <dir>`
+    // actually sticks for the commands that follow it.
+    let resolved_workspace = if matches!(cli.command, Commands::Init { .. }) {
+        workspace.clone()
+    } else {
+        read_cache(json_path)
+            .map(|cache| PathBuf::from(cache.workspace))
+            .unwrap_or_else(|_| workspace.clone())
+    };
+    let workspace = &resolved_workspace;
+
     match &cli.command {
-        Commands::Init => {
-            create_json_in_workspace(json_path, workspace);
+        Commands::Init { entry } => {
+            let entry_workspace = entry.clone().unwrap_or_else(|| workspace.clone());
+            create_json_in_workspace(json_path, &entry_workspace);
         }
         Commands::SelectCurrentBuild => {
             select_current_build_target(json_path)?;
         }
         Commands::Build => {
             build_current_target(json_path, workspace)?;
+            generate_build_manifest(json_path, workspace)?;
         }
         Commands::Run => {
             run_current_target(json_path, workspace)?;
@@ -299,6 +1333,20 @@ fn cli_parser(workspace: &PathBuf, json_path: &PathBuf) -> Result<(), Box<dyn Er
         Commands::Configure => {
             configure_current_build_target(json_path, workspace)?;
         }
+        Commands::Watch { run } => {
+            watch_workspace(json_path, workspace, *run)?;
+        }
+        Commands::Plan { target } => {
+            let planned = match target {
+                PlanTarget::Configure => plan_configure(json_path, workspace)?,
+                PlanTarget::Build => plan_build(json_path, workspace)?,
+                PlanTarget::Run => plan_run(json_path, workspace)?,
+            };
+            print_plan(&planned)?;
+        }
+        Commands::Verify => {
+            verify_build_manifest(json_path, workspace)?;
+        }
     }
     Ok(())
 }
@@ -309,6 +1357,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     const CMAKE_FORGE_DIR: &str = "CMakeForge";
     const JSON_EXTENSION: &str = ".json";
 
+    let cli = Cli::parse();
+
     // Get the current working directory
     let exe_path = env::current_dir().expect("Failed to get current directory");
     println!("Executable Path: {}", exe_path.display());
@@ -339,8 +1389,18 @@ fn main() -> Result<(), Box<dyn Error>> {
         println!("Directory created: {}", cache_path.display());
     }
 
+    // The workspace this invocation targets: `init --entry <dir>` scaffolds
+    // a directory other than the current one, so the cache it writes must
+    // be keyed off that directory's name, not the invoking cwd's.
+    let invocation_workspace = match &cli.command {
+        Commands::Init {
+            entry: Some(entry),
+        } => entry.clone(),
+        _ => exe_path.clone(),
+    };
+
     // Deduce project name
-    let project_name_deduced = match exe_path.file_name() {
+    let project_name_deduced = match invocation_workspace.file_name() {
         Some(name) => format!("{}{}", name.to_string_lossy(), JSON_EXTENSION),
         None => {
             return Err("Failed to deduce project name.".into());
@@ -351,7 +1411,11 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     // Call the CLI parser
-    cli_parser(&exe_path, &cache_path.join(project_name_deduced))
+    cli_parser(
+        cli,
+        &invocation_workspace,
+        &cache_path.join(project_name_deduced),
+    )
 }
 
 fn read_cache(json_path: &PathBuf) -> Result<CacheJson, Box<dyn Error>> {